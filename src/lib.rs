@@ -1,11 +1,12 @@
-#[cfg(test)]
 pub mod ring_buffer;
+
+#[cfg(test)]
 mod tests {
     use crate::ring_buffer::{RingBuffer, RingBufferError};
     
     #[test]
     fn it_works() {
-        let mut buff = RingBuffer::<u8, 1024>::new()
+        let mut buff = RingBuffer::<u8>::new(1024)
             .expect("Allocation should be successful in this test case");
         || -> Result<(), RingBufferError<_>> {
             buff.push_value(32)?;
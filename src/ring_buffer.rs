@@ -1,10 +1,45 @@
-use std::{alloc, mem, ptr};
+use std::{
+    alloc,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    ptr, slice,
+};
 
-pub struct RingBuffer<T: Copy, const N: usize> {
-    buffer: *const T,
+mod sealed {
+    /// Sealed marker trait selecting what `push_value` does once the buffer is full.
+    ///
+    /// Sealing keeps [`Bounded`] and [`Unbounded`] as the only valid modes.
+    pub trait Mode {
+        const OVERWRITE_ON_FULL: bool;
+    }
+
+    /// `push_value` returns `OverflowError` once the buffer is full.
+    pub struct Bounded;
+    /// `push_value` overwrites the oldest element once the buffer is full.
+    pub struct Unbounded;
+
+    impl Mode for Bounded {
+        const OVERWRITE_ON_FULL: bool = false;
+    }
+
+    impl Mode for Unbounded {
+        const OVERWRITE_ON_FULL: bool = true;
+    }
+}
+
+pub use sealed::{Bounded, Unbounded};
+
+/// Lowest capacity a `RingBuffer` is ever allocated at, regardless of what
+/// `new` or `shrink_to_fit` are asked for.
+const MINIMUM_CAPACITY: usize = 2;
+
+pub struct RingBuffer<T, Mode: sealed::Mode = Bounded> {
+    buffer: *mut T,
+    cap: usize,
     is_empty: bool,
     head: *mut T,
     tail: *mut T,
+    mode: PhantomData<Mode>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -14,33 +49,36 @@ pub enum RingBufferError<T> {
     OverflowError(T),
 }
 
-impl<T: Copy, const N: usize> RingBuffer<T, N> {
-    pub fn new() -> Result<Self, RingBufferError<T>> {
+impl<T, Mode: sealed::Mode> RingBuffer<T, Mode> {
+    pub fn new(capacity: usize) -> Result<Self, RingBufferError<T>> {
+        let cap = capacity.max(MINIMUM_CAPACITY);
         unsafe {
-            let element_size = mem::size_of::<T>().checked_next_power_of_two();
-            if element_size == None {
-                return Err(RingBufferError::InitializationLayoutError);
-            }
-
-            let buffer_layout = alloc::Layout::from_size_align(N, element_size.unwrap())
-                .map_err(|_| RingBufferError::InitializationLayoutError)?;
-            let buffer = alloc::alloc_zeroed(buffer_layout) as *mut T;
-            if buffer == ptr::null_mut() {
-                return Err(RingBufferError::InitializationAllocationError);
-            }
+            let buffer = Self::alloc_buffer(cap)?;
             Ok(Self {
                 buffer,
+                cap,
                 is_empty: true,
                 head: buffer,
                 tail: buffer,
+                mode: PhantomData,
             })
         }
     }
 
+    unsafe fn alloc_buffer(cap: usize) -> Result<*mut T, RingBufferError<T>> {
+        let buffer_layout =
+            alloc::Layout::array::<T>(cap).map_err(|_| RingBufferError::InitializationLayoutError)?;
+        let buffer = alloc::alloc_zeroed(buffer_layout) as *mut T;
+        if buffer.is_null() {
+            return Err(RingBufferError::InitializationAllocationError);
+        }
+        Ok(buffer)
+    }
+
     pub fn next_value(&mut self) -> Option<T> {
         unsafe {
             if !self.is_empty {
-                let value = *self.head;
+                let value = ptr::read(self.head);
                 self.head = self.next_pointer_value(self.head) as *mut T;
                 if self.head == self.tail {
                     self.is_empty = true;
@@ -55,9 +93,20 @@ impl<T: Copy, const N: usize> RingBuffer<T, N> {
     pub fn push_value(&mut self, value: T) -> Result<(), RingBufferError<T>> {
         unsafe {
             if self.is_overflow() {
-                Err(RingBufferError::OverflowError(value))
+                if Mode::OVERWRITE_ON_FULL {
+                    // Buffer is full: `tail` and `head` are the same slot and still
+                    // hold the oldest live element, so it must be dropped before we
+                    // overwrite it and evict it from the readable region.
+                    ptr::drop_in_place(self.tail);
+                    ptr::write(self.tail, value);
+                    self.tail = self.next_pointer_value(self.tail) as *mut T;
+                    self.head = self.next_pointer_value(self.head) as *mut T;
+                    Ok(())
+                } else {
+                    Err(RingBufferError::OverflowError(value))
+                }
             } else {
-                *self.tail = value;
+                ptr::write(self.tail, value);
                 self.tail = self.next_pointer_value(self.tail) as *mut T;
                 self.is_empty = false;
                 Ok(())
@@ -65,13 +114,206 @@ impl<T: Copy, const N: usize> RingBuffer<T, N> {
         }
     }
 
+    /// Pushes elements from `iter` until the buffer is full, then stops.
+    ///
+    /// Returns the number of elements actually stored; in `Bounded` mode this
+    /// can be fewer than `iter` yields. Use [`push_from_iter_overflowing`] on an
+    /// `Unbounded` buffer to consume the whole iterator instead.
+    ///
+    /// [`push_from_iter_overflowing`]: RingBuffer::push_from_iter_overflowing
+    pub fn push_from_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let mut iter = iter.into_iter();
+        let mut pushed = 0;
+
+        'outer: loop {
+            let free = self.cap - self.len();
+            if free == 0 {
+                break;
+            }
+
+            let tail_idx = self.index_of(self.tail);
+            let run_len = free.min(self.cap - tail_idx);
+
+            for offset in 0..run_len {
+                let value = match iter.next() {
+                    Some(value) => value,
+                    None => {
+                        unsafe {
+                            self.tail = self.tail.add(offset);
+                        }
+                        if offset > 0 {
+                            self.is_empty = false;
+                        }
+                        pushed += offset;
+                        break 'outer;
+                    }
+                };
+                unsafe {
+                    ptr::write(self.tail.add(offset), value);
+                }
+            }
+
+            unsafe {
+                self.tail = self.buffer.add((tail_idx + run_len) % self.cap);
+            }
+            self.is_empty = false;
+            pushed += run_len;
+        }
+
+        pushed
+    }
+
+    /// Borrows the readable region as exact, non-wrapping `chunk_size` slices.
+    ///
+    /// Chunks are drawn from the contiguous run starting at `head`; the iterator
+    /// stops (like [`slice::chunks_exact`]) once fewer than `chunk_size` elements
+    /// remain before the wrap point, without continuing into the wrapped segment.
+    /// Call [`align_skip`] first to make the first chunk start at an allocation
+    /// offset that is a multiple of `chunk_size`.
+    ///
+    /// [`align_skip`]: RingBuffer::align_skip
+    pub fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<'_, T> {
+        assert!(chunk_size > 0, "chunk_size must be non-zero");
+
+        let head_idx = self.index_of(self.head);
+        let contiguous_len = self.len().min(self.cap - head_idx);
+        ChunksExactMut {
+            ptr: self.head,
+            remaining: contiguous_len,
+            chunk_size,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Drops up to `chunk_size - 1` leading elements so `head` sits at an offset
+    /// (relative to the start of the allocation) that is a multiple of `chunk_size`.
+    ///
+    /// Intended to run before [`chunks_exact_mut`] so its first chunk starts aligned.
+    ///
+    /// [`chunks_exact_mut`]: RingBuffer::chunks_exact_mut
+    pub fn align_skip(&mut self, chunk_size: usize) {
+        assert!(chunk_size > 0, "chunk_size must be non-zero");
+
+        let head_idx = self.index_of(self.head);
+        let offset = (chunk_size - head_idx % chunk_size) % chunk_size;
+        let skip = offset.min(self.len());
+        for _ in 0..skip {
+            self.next_value();
+        }
+    }
+
+    /// Grows capacity so at least `additional` more elements can be pushed
+    /// without overflowing, reallocating if necessary.
+    ///
+    /// The new capacity is the next power of two at or above the required
+    /// size. The live elements are copied into the new allocation in order,
+    /// linearized so `head` lands at offset `0`.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len() + additional;
+        if required <= self.cap {
+            return;
+        }
+
+        let new_cap = required.max(MINIMUM_CAPACITY).next_power_of_two();
+        self.resize_to(new_cap);
+    }
+
+    /// Shrinks capacity to fit the current length, down to `MINIMUM_CAPACITY`.
+    pub fn shrink_to_fit(&mut self) {
+        let new_cap = self.len().max(MINIMUM_CAPACITY).next_power_of_two();
+        if new_cap >= self.cap {
+            return;
+        }
+
+        self.resize_to(new_cap);
+    }
+
+    /// Reallocates the backing store at `new_cap` and relocates the live
+    /// region there, linearized so `head` lands at offset `0`.
+    fn resize_to(&mut self, new_cap: usize) {
+        unsafe {
+            let new_layout = alloc::Layout::array::<T>(new_cap).unwrap();
+            let new_buffer = alloc::alloc_zeroed(new_layout) as *mut T;
+            if new_buffer.is_null() {
+                alloc::handle_alloc_error(new_layout);
+            }
+
+            let len = self.len();
+            let head_idx = self.index_of(self.head);
+            let straight_run = len.min(self.cap - head_idx);
+            ptr::copy_nonoverlapping(self.head, new_buffer, straight_run);
+
+            let remaining = len - straight_run;
+            if remaining > 0 {
+                ptr::copy_nonoverlapping(self.buffer, new_buffer.add(straight_run), remaining);
+            }
+
+            self.free_buffer();
+            self.buffer = new_buffer;
+            self.cap = new_cap;
+            self.head = new_buffer;
+            // `len % new_cap` wraps `tail` back to offset 0 when the relocated
+            // region exactly fills the new allocation, matching every other
+            // path in this file where a full buffer has `tail == head`.
+            self.tail = new_buffer.add(len % new_cap);
+        }
+    }
+
     fn is_overflow(&self) -> bool {
         !self.is_empty && (self.tail == self.head)
     }
 
+    /// Number of elements currently stored in the buffer.
+    pub fn len(&self) -> usize {
+        if self.is_empty {
+            return 0;
+        }
+
+        let head_idx = self.index_of(self.head);
+        let tail_idx = self.index_of(self.tail);
+        if tail_idx > head_idx {
+            tail_idx - head_idx
+        } else if tail_idx < head_idx {
+            self.cap - head_idx + tail_idx
+        } else {
+            self.cap
+        }
+    }
+
+    /// Returns `true` if the buffer holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.is_empty
+    }
+
+    /// Returns a reference to the next element that would be popped, without
+    /// removing it.
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty {
+            None
+        } else {
+            unsafe { Some(&*self.head) }
+        }
+    }
+
+    /// Iterates over the live elements from `head` to `tail`, without consuming them.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            buffer_start: self.buffer,
+            buffer_end: unsafe { self.buffer.add(self.cap - 1) },
+            cur: self.head,
+            remaining: self.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Offset of `ptr` from the start of the backing allocation, in elements.
+    fn index_of(&self, ptr: *const T) -> usize {
+        unsafe { ptr.offset_from(self.buffer as *const T) as usize }
+    }
+
     fn next_pointer_value(&self, ptr: *const T) -> *const T {
         unsafe {
-            let buffer_end = self.buffer.offset(N as isize - 1);
+            let buffer_end = (self.buffer as *const T).offset(self.cap as isize - 1);
             if ptr.offset(1) > buffer_end {
                 self.buffer
             } else {
@@ -82,28 +324,179 @@ impl<T: Copy, const N: usize> RingBuffer<T, N> {
 
     fn free_buffer(&mut self) {
         unsafe {
-            let buffer_layout =
-                alloc::Layout::from_size_align(N, mem::size_of::<T>().next_power_of_two()).unwrap();
+            let buffer_layout = alloc::Layout::array::<T>(self.cap).unwrap();
             alloc::dealloc(self.buffer as *mut u8, buffer_layout);
         }
     }
+
+    /// Drops every element still live in the buffer, respecting the wrap.
+    fn drop_live_elements(&mut self) {
+        if self.is_empty {
+            return;
+        }
+
+        let head_idx = self.index_of(self.head);
+        let tail_idx = self.index_of(self.tail);
+        unsafe {
+            if tail_idx > head_idx {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.head, tail_idx - head_idx));
+            } else {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.head, self.cap - head_idx));
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.buffer, tail_idx));
+            }
+        }
+    }
+}
+
+impl<T: Copy, Mode: sealed::Mode> RingBuffer<T, Mode> {
+    /// Pops as many elements as fit into `out`, in FIFO order.
+    ///
+    /// Returns the number of elements written, which is `out.len().min(self.len())`.
+    pub fn fill_slice(&mut self, out: &mut [T]) -> usize {
+        let available = self.len();
+        let to_copy = out.len().min(available);
+        if to_copy == 0 {
+            return 0;
+        }
+
+        let head_idx = self.index_of(self.head);
+        let straight_run = to_copy.min(self.cap - head_idx);
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.head, out.as_mut_ptr(), straight_run);
+        }
+
+        let remaining = to_copy - straight_run;
+        if remaining > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.buffer, out.as_mut_ptr().add(straight_run), remaining);
+            }
+        }
+
+        unsafe {
+            self.head = self.buffer.add((head_idx + to_copy) % self.cap);
+        }
+        if to_copy == available {
+            self.is_empty = true;
+        }
+
+        to_copy
+    }
+}
+
+/// Iterator over exact, non-wrapping chunks yielded by [`RingBuffer::chunks_exact_mut`].
+pub struct ChunksExactMut<'a, T> {
+    ptr: *mut T,
+    remaining: usize,
+    chunk_size: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for ChunksExactMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining < self.chunk_size {
+            return None;
+        }
+
+        let chunk = unsafe { slice::from_raw_parts_mut(self.ptr, self.chunk_size) };
+        self.ptr = unsafe { self.ptr.add(self.chunk_size) };
+        self.remaining -= self.chunk_size;
+        Some(chunk)
+    }
+}
+
+/// Iterator over the live elements yielded by [`RingBuffer::iter`].
+pub struct Iter<'a, T> {
+    buffer_start: *const T,
+    buffer_end: *const T,
+    cur: *const T,
+    remaining: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let value = unsafe { &*self.cur };
+        self.cur = if self.cur >= self.buffer_end {
+            self.buffer_start
+        } else {
+            unsafe { self.cur.add(1) }
+        };
+        self.remaining -= 1;
+        Some(value)
+    }
+}
+
+impl<T, Mode: sealed::Mode> Index<usize> for RingBuffer<T, Mode> {
+    type Output = T;
+
+    /// Index `0` is the element at `head`; indices count toward `tail`, wrapping
+    /// through the allocation. Panics if `index >= self.len()`.
+    fn index(&self, index: usize) -> &T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+        let target = (self.index_of(self.head) + index) % self.cap;
+        unsafe { &*self.buffer.add(target) }
+    }
+}
+
+impl<T, Mode: sealed::Mode> IndexMut<usize> for RingBuffer<T, Mode> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+        let target = (self.index_of(self.head) + index) % self.cap;
+        unsafe { &mut *self.buffer.add(target) }
+    }
+}
+
+impl<T> RingBuffer<T, Unbounded> {
+    /// Pushes every element of `iter`, overwriting the oldest elements as needed.
+    ///
+    /// Returns how many elements were consumed from `iter` (always all of them,
+    /// since `Unbounded` buffers never reject a push).
+    pub fn push_from_iter_overflowing<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let mut consumed = 0;
+        for value in iter.into_iter() {
+            self.push_value(value)
+                .unwrap_or_else(|_| unreachable!("push_value never fails in Unbounded mode"));
+            consumed += 1;
+        }
+        consumed
+    }
 }
 
-impl<T: Copy, const N: usize> Drop for RingBuffer<T, N> {
+impl<T, Mode: sealed::Mode> Drop for RingBuffer<T, Mode> {
     fn drop(&mut self) {
-        self.free_buffer()
+        self.drop_live_elements();
+        self.free_buffer();
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::{cell::Cell, rc::Rc};
+
     use crate::ring_buffer::RingBufferError;
 
-    use super::RingBuffer;
+    use super::{RingBuffer, Unbounded};
 
     #[test]
     fn test_should_get_four_pushed_values_in_same_order() {
-        let mut buff = RingBuffer::<u8, 1024>::new()
+        let mut buff = RingBuffer::<u8>::new(1024)
             .expect("Allocation should be successful in this test case");
         || -> Result<(), RingBufferError<_>> {
             buff.push_value(32)?;
@@ -122,14 +515,14 @@ mod test {
 
     #[test]
     fn test_should_get_none_when_buffer_empty_without_pushing_values() {
-        let mut buff = RingBuffer::<u8, 1024>::new()
+        let mut buff = RingBuffer::<u8>::new(1024)
             .expect("Allocation should be successful in this test case");
         assert_eq!(None, buff.next_value())
     }
 
     #[test]
     fn test_should_get_none_when_buffer_empty_after_pushing_and_getting_values() {
-        let mut buff = RingBuffer::<u8, 1024>::new()
+        let mut buff = RingBuffer::<u8>::new(1024)
             .expect("Allocation should be successful in this test case");
         || -> Result<(), RingBufferError<_>> {
             buff.push_value(32)?;
@@ -151,7 +544,7 @@ mod test {
     fn test_should_get_overflow_error_with_latest_insert_value_when_buffer_push_more_values_than_capacity(
     ) {
         let mut buff =
-            RingBuffer::<u8, 3>::new().expect("Allocation should be successful in this test case");
+            RingBuffer::<u8>::new(3).expect("Allocation should be successful in this test case");
         let latest = 33;
         let result = || -> Result<(), RingBufferError<u8>> {
             buff.push_value(32)?;
@@ -166,7 +559,7 @@ mod test {
     #[test]
     fn test_should_push_without_error_after_consuming_few_values_from_full_buffer() {
         let mut buff =
-            RingBuffer::<u8, 4>::new().expect("Allocation should be successful in this test case");
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
         let latest = 33;
         || -> Result<(), RingBufferError<u8>> {
             buff.push_value(32)?;
@@ -189,4 +582,367 @@ mod test {
 
         assert_eq!(Some(expected_value), buff.next_value());
     }
+
+    #[test]
+    fn test_should_overwrite_oldest_value_when_unbounded_buffer_is_full() {
+        let mut buff = RingBuffer::<u8, Unbounded>::new(3)
+            .expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+        buff.push_value(2).unwrap();
+        buff.push_value(3).unwrap();
+        // Buffer is full; this push should overwrite `1` instead of erroring.
+        assert_eq!(Ok(()), buff.push_value(4));
+
+        assert_eq!(Some(2), buff.next_value());
+        assert_eq!(Some(3), buff.next_value());
+        assert_eq!(Some(4), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_push_from_iter_should_stop_once_buffer_is_full() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+
+        let pushed = buff.push_from_iter(1..=10);
+        assert_eq!(4, pushed);
+
+        assert_eq!(Some(1), buff.next_value());
+        assert_eq!(Some(2), buff.next_value());
+        assert_eq!(Some(3), buff.next_value());
+        assert_eq!(Some(4), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_push_from_iter_should_handle_wrap_around() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+
+        buff.push_value(1).unwrap();
+        buff.push_value(2).unwrap();
+        buff.next_value();
+        buff.next_value();
+
+        let pushed = buff.push_from_iter([3, 4, 5, 6]);
+        assert_eq!(4, pushed);
+
+        assert_eq!(Some(3), buff.next_value());
+        assert_eq!(Some(4), buff.next_value());
+        assert_eq!(Some(5), buff.next_value());
+        assert_eq!(Some(6), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_push_from_iter_overflowing_should_consume_everything_and_overwrite() {
+        let mut buff = RingBuffer::<u8, Unbounded>::new(3)
+            .expect("Allocation should be successful in this test case");
+
+        let consumed = buff.push_from_iter_overflowing(1..=5);
+        assert_eq!(5, consumed);
+
+        assert_eq!(Some(3), buff.next_value());
+        assert_eq!(Some(4), buff.next_value());
+        assert_eq!(Some(5), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_fill_slice_should_pop_as_many_as_fit_and_report_the_count() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+        buff.push_value(2).unwrap();
+        buff.push_value(3).unwrap();
+
+        let mut out = [0u8; 2];
+        let popped = buff.fill_slice(&mut out);
+        assert_eq!(2, popped);
+        assert_eq!([1, 2], out);
+
+        assert_eq!(Some(3), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_fill_slice_should_handle_wrap_around() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+        buff.push_value(2).unwrap();
+        buff.push_value(3).unwrap();
+        buff.next_value();
+        buff.next_value();
+        buff.push_value(4).unwrap();
+        buff.push_value(5).unwrap();
+
+        let mut out = [0u8; 3];
+        let popped = buff.fill_slice(&mut out);
+        assert_eq!(3, popped);
+        assert_eq!([3, 4, 5], out);
+    }
+
+    #[test]
+    fn test_chunks_exact_mut_should_yield_exact_chunks_from_the_first_contiguous_run() {
+        let mut buff =
+            RingBuffer::<u8>::new(8).expect("Allocation should be successful in this test case");
+        buff.push_from_iter(1..=7);
+
+        let chunks: Vec<Vec<u8>> = buff
+            .chunks_exact_mut(2)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5, 6]], chunks);
+    }
+
+    #[test]
+    fn test_chunks_exact_mut_should_not_straddle_the_wrap_boundary() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+        buff.push_value(2).unwrap();
+        buff.next_value();
+        buff.next_value();
+        // head is now at index 2; only 2 elements remain before buffer_end.
+        buff.push_value(3).unwrap();
+        buff.push_value(4).unwrap();
+        buff.push_value(5).unwrap();
+
+        let chunks: Vec<Vec<u8>> = buff
+            .chunks_exact_mut(2)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        // The wrapped `5` at index 0 is never included: the iterator stops at
+        // the wrap point instead of continuing into the wrapped segment.
+        assert_eq!(vec![vec![3, 4]], chunks);
+    }
+
+    #[test]
+    fn test_align_skip_should_drop_leading_elements_until_head_is_aligned() {
+        let mut buff =
+            RingBuffer::<u8>::new(8).expect("Allocation should be successful in this test case");
+        buff.push_from_iter(1..=8);
+        buff.next_value();
+        // head is now at index 1; aligning to 4 drops the next three elements (2, 3, 4).
+
+        buff.align_skip(4);
+
+        assert_eq!(Some(5), buff.next_value());
+        assert_eq!(Some(6), buff.next_value());
+        assert_eq!(Some(7), buff.next_value());
+        assert_eq!(Some(8), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_len_and_is_empty_should_track_pushes_and_pops() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        assert_eq!(0, buff.len());
+        assert!(buff.is_empty());
+
+        buff.push_value(1).unwrap();
+        buff.push_value(2).unwrap();
+        assert_eq!(2, buff.len());
+        assert!(!buff.is_empty());
+
+        buff.next_value();
+        buff.next_value();
+        assert_eq!(0, buff.len());
+        assert!(buff.is_empty());
+    }
+
+    #[test]
+    fn test_peek_should_return_the_next_value_without_consuming_it() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        assert_eq!(None, buff.peek());
+
+        buff.push_value(7).unwrap();
+        assert_eq!(Some(&7), buff.peek());
+        assert_eq!(Some(&7), buff.peek());
+        assert_eq!(Some(7), buff.next_value());
+    }
+
+    #[test]
+    fn test_index_should_read_elements_from_head_toward_tail() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+        buff.next_value();
+        buff.push_value(2).unwrap();
+        buff.push_value(3).unwrap();
+        buff.push_value(4).unwrap();
+
+        assert_eq!(2, buff[0]);
+        assert_eq!(3, buff[1]);
+        assert_eq!(4, buff[2]);
+
+        buff[1] = 99;
+        assert_eq!(99, buff[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_should_panic_when_out_of_bounds() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+
+        let _ = buff[1];
+    }
+
+    #[test]
+    fn test_iter_should_walk_live_elements_from_head_to_tail() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+        buff.next_value();
+        buff.push_from_iter([2, 3, 4]);
+
+        assert_eq!(vec![&2, &3, &4], buff.iter().collect::<Vec<_>>());
+        // `iter` doesn't consume elements.
+        assert_eq!(3, buff.len());
+    }
+
+    #[test]
+    fn test_iter_should_walk_a_full_buffer() {
+        let mut buff =
+            RingBuffer::<u8>::new(3).expect("Allocation should be successful in this test case");
+        buff.push_from_iter([1, 2, 3]);
+
+        assert_eq!(vec![&1, &2, &3], buff.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_reserve_should_grow_capacity_and_preserve_order_across_wrap() {
+        let mut buff =
+            RingBuffer::<u8>::new(4).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+        buff.push_value(2).unwrap();
+        buff.next_value();
+        buff.next_value();
+        buff.push_value(3).unwrap();
+        buff.push_value(4).unwrap();
+        buff.push_value(5).unwrap();
+        // head/tail now straddle the wrap boundary.
+
+        buff.reserve(10);
+
+        assert_eq!(Some(3), buff.next_value());
+        assert_eq!(Some(4), buff.next_value());
+        assert_eq!(Some(5), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_reserve_should_be_a_no_op_when_capacity_already_suffices() {
+        let mut buff =
+            RingBuffer::<u8>::new(8).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+
+        buff.reserve(3);
+
+        assert_eq!(Some(1), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_shrink_to_fit_should_reduce_capacity_down_to_minimum() {
+        let mut buff =
+            RingBuffer::<u8>::new(64).expect("Allocation should be successful in this test case");
+        buff.push_value(1).unwrap();
+
+        buff.shrink_to_fit();
+        // One live element is below the minimum floor, so capacity shrinks to 2,
+        // leaving room for exactly one more element.
+        assert_eq!(Ok(()), buff.push_value(2));
+        assert_eq!(Err(RingBufferError::OverflowError(3)), buff.push_value(3));
+
+        assert_eq!(Some(1), buff.next_value());
+        assert_eq!(Some(2), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_shrink_to_fit_should_wrap_tail_when_len_exactly_fills_new_capacity() {
+        let mut buff =
+            RingBuffer::<u8>::new(8).expect("Allocation should be successful in this test case");
+        buff.push_from_iter(1..=4);
+
+        // `len()` (4) is already an exact power of two, so the new capacity
+        // equals `len()` and `tail` must wrap to offset 0, same as `head`.
+        buff.shrink_to_fit();
+
+        assert_eq!(Err(RingBufferError::OverflowError(5)), buff.push_value(5));
+
+        assert_eq!(Some(1), buff.next_value());
+        assert_eq!(Some(2), buff.next_value());
+        assert_eq!(Some(3), buff.next_value());
+        assert_eq!(Some(4), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[test]
+    fn test_should_store_and_retrieve_owned_non_copy_values() {
+        let mut buff = RingBuffer::<String>::new(4)
+            .expect("Allocation should be successful in this test case");
+        buff.push_value(String::from("first")).unwrap();
+        buff.push_value(String::from("second")).unwrap();
+
+        assert_eq!(Some(String::from("first")), buff.next_value());
+        assert_eq!(Some(String::from("second")), buff.next_value());
+        assert_eq!(None, buff.next_value());
+    }
+
+    #[derive(Debug)]
+    struct DropCounter(Rc<Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_drop_should_run_destructors_for_every_live_element_exactly_once() {
+        let drops = Rc::new(Cell::new(0));
+
+        {
+            let mut buff = RingBuffer::<DropCounter>::new(4)
+                .expect("Allocation should be successful in this test case");
+            buff.push_value(DropCounter(drops.clone())).unwrap();
+            buff.push_value(DropCounter(drops.clone())).unwrap();
+            buff.push_value(DropCounter(drops.clone())).unwrap();
+            buff.push_value(DropCounter(drops.clone())).unwrap();
+
+            // These two are consumed and dropped here, as their own scope ends.
+            drop(buff.next_value());
+            drop(buff.next_value());
+            assert_eq!(2, drops.get());
+
+            // The other two are still live inside `buff` when it goes out of scope below.
+        }
+
+        assert_eq!(4, drops.get());
+    }
+
+    #[test]
+    fn test_unbounded_overwrite_should_drop_the_evicted_element_exactly_once() {
+        let drops = Rc::new(Cell::new(0));
+
+        let mut buff = RingBuffer::<DropCounter, Unbounded>::new(2)
+            .expect("Allocation should be successful in this test case");
+        buff.push_value(DropCounter(drops.clone())).unwrap();
+        buff.push_value(DropCounter(drops.clone())).unwrap();
+        // Buffer is full; overwriting must drop the evicted oldest element.
+        buff.push_value(DropCounter(drops.clone())).unwrap();
+        assert_eq!(1, drops.get());
+
+        drop(buff);
+        assert_eq!(3, drops.get());
+    }
 }